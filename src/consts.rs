@@ -25,6 +25,9 @@ pub const GLUE_MARKER: &'static str = "<>";
 /// (or the end of the line) will be a single tag.
 pub const TAG_MARKER: char = '#';
 
+/// Characters which are added as-is to tag text when preceded by a backslash.
+pub const TAG_ESCAPED_CHARS: &[char] = &[TAG_MARKER, '{', '}', '\\'];
+
 /********************
  * Sequence markers *
  ********************/
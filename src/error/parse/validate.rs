@@ -4,6 +4,10 @@
 //! This pass will check for errors in expressions, conditions, naming and assignments
 //! throughout the entire story. Any invalid types or names will yield a
 //! [`ValidationError`][crate::error::parse::validate::ValidationError].
+//!
+//! Content which is valid but likely a mistake yields a
+//! [`ValidationWarning`][crate::error::parse::validate::ValidationWarning] instead.
+//! Warnings do not stop the story from being read.
 
 use crate::error::{
     parse::address::InvalidAddressError,
@@ -12,6 +16,9 @@ use crate::error::{
     InklingError,
 };
 
+#[cfg(feature = "serde_support")]
+use serde::{Deserialize, Serialize};
+
 use std::{
     error::Error,
     fmt::{self, Write},
@@ -101,6 +108,25 @@ pub enum InvalidVariableExpressionError {
     Internal(InklingError),
 }
 
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde_support", derive(Deserialize, Serialize))]
+/// Warning about story content which is valid but likely a mistake.
+pub enum ValidationWarning {
+    /// A condition evaluates to the same value regardless of the story state.
+    ///
+    /// Only values written directly in the condition are known when the story is read,
+    /// not the values of variables or number of visits to knots and stitches.
+    /// Some conditions can still be determined, for example `{x > 2 or true}`.
+    /// If the value is `false`, any content or choice that is gated by the condition
+    /// will never be shown.
+    ConstantCondition {
+        /// Value that the condition always evaluates to.
+        value: bool,
+        /// Information about the origin of the line containing the condition.
+        meta_data: MetaData,
+    },
+}
+
 #[derive(Debug)]
 /// Error type for name space collisions.
 pub struct NameSpaceCollision {
@@ -204,6 +230,25 @@ impl fmt::Display for ExpressionKind {
     }
 }
 
+impl fmt::Display for ValidationWarning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self {
+            ValidationWarning::ConstantCondition { value, meta_data } => {
+                write_line_information(f, meta_data)?;
+
+                if *value {
+                    write!(f, "Condition is always true")
+                } else {
+                    write!(
+                        f,
+                        "Condition is always false: its content will never be shown"
+                    )
+                }
+            }
+        }
+    }
+}
+
 impl fmt::Display for NameSpaceCollision {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write_line_information(f, &self.from_meta_data)?;
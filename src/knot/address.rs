@@ -8,7 +8,7 @@ use crate::{
     error::{
         parse::{
             address::{InvalidAddressError, InvalidAddressErrorKind},
-            validate::ValidationError,
+            validate::{ValidationError, ValidationWarning},
         },
         utils::MetaData,
         InternalError,
@@ -179,6 +179,7 @@ impl ValidateContent for Address {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        _: &mut Vec<ValidationWarning>,
         current_location: &Address,
         meta_data: &MetaData,
         data: &ValidationData,
@@ -354,7 +355,13 @@ pub mod tests {
     ) -> Result<(), InvalidAddressError> {
         let mut error = ValidationError::new();
 
-        address.validate(&mut error, current_location, &().into(), data);
+        address.validate(
+            &mut error,
+            &mut Vec::new(),
+            current_location,
+            &().into(),
+            data,
+        );
 
         if error.is_empty() {
             Ok(())
@@ -10,4 +10,5 @@ pub use stitch::{
 };
 pub use utils::{
     get_empty_knot_counts, get_mut_stitch, get_num_visited, get_stitch, increment_num_visited,
+    set_num_visited,
 };
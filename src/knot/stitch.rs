@@ -99,6 +99,15 @@ impl Stitch {
         Ok(result)
     }
 
+    /// Reset the stitch to the state it had before the story was followed.
+    ///
+    /// Resets the stack to the first line and all alternatives and visited choices
+    /// in the content.
+    pub fn reset(&mut self) {
+        self.root.reset();
+        self.reset_stack();
+    }
+
     /// Reset the current stack to the first line of the root node.
     fn reset_stack(&mut self) {
         self.stack = vec![0];
@@ -73,6 +73,24 @@ pub fn increment_num_visited(
         )
 }
 
+pub fn set_num_visited(
+    address: &Address,
+    num_visited: u32,
+    data: &mut FollowData,
+) -> Result<(), InternalError> {
+    let (knot_name, stitch_name) = address.get_knot_and_stitch()?;
+
+    data.knot_visit_counts
+        .get_mut(knot_name)
+        .and_then(|knot| knot.get_mut(stitch_name).map(|count| *count = num_visited))
+        .ok_or(
+            StackError::BadAddress {
+                address: address.clone(),
+            }
+            .into(),
+        )
+}
+
 pub fn get_empty_knot_counts(knots: &KnotSet) -> HashMap<String, HashMap<String, u32>> {
     knots
         .iter()
@@ -171,7 +171,8 @@ mod story;
 mod utils;
 
 pub use error::InklingError;
-pub use line::Variable;
+pub use line::{Variable, VariableType};
 pub use story::{
-    copy_lines_into_string, read_story_from_string, Choice, Line, LineBuffer, Prompt, Story,
+    copy_lines_into_string, read_story_from_string, Choice, ChoiceNumbering, Line, LineBuffer,
+    Prompt, Story, TranscriptEntry,
 };
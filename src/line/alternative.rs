@@ -1,7 +1,10 @@
 //! Content that alternates from a fixed set when processed.
 
 use crate::{
-    error::{parse::validate::ValidationError, utils::MetaData},
+    error::{
+        parse::validate::{ValidationError, ValidationWarning},
+        utils::MetaData,
+    },
     knot::Address,
     line::LineChunk,
     story::validate::{ValidateContent, ValidationData},
@@ -53,17 +56,28 @@ pub enum AlternativeKind {
     Sequence,
 }
 
+impl Alternative {
+    /// Reset the alternative to its initial state, before any item has been selected.
+    ///
+    /// Nested alternatives in the set of items are also reset.
+    pub fn reset(&mut self) {
+        self.current_index = None;
+        self.items.iter_mut().for_each(|item| item.reset());
+    }
+}
+
 impl ValidateContent for Alternative {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         meta_data: &MetaData,
         data: &ValidationData,
     ) {
         self.items
             .iter_mut()
-            .for_each(|item| item.validate(error, current_location, meta_data, data));
+            .for_each(|item| item.validate(error, warnings, current_location, meta_data, data));
     }
 }
 
@@ -1,7 +1,10 @@
 //! Choice which branches the story.
 
 use crate::{
-    error::{parse::validate::ValidationError, utils::MetaData},
+    error::{
+        parse::validate::{ValidationError, ValidationWarning},
+        utils::MetaData,
+    },
     knot::Address,
     line::{Condition, InternalLine},
     story::validate::{ValidateContent, ValidationData},
@@ -68,19 +71,38 @@ impl PartialEq for InternalChoice {
     }
 }
 
+impl InternalChoice {
+    /// Reset all alternatives in the selection and display text to their initial state.
+    pub fn reset(&mut self) {
+        self.selection_text.lock().unwrap().reset();
+        self.display_text.reset();
+    }
+}
+
 impl ValidateContent for InternalChoice {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         _: &MetaData,
         data: &ValidationData,
     ) {
         let num_address_errors = error.invalid_address_errors.len();
 
+        let num_shared_items = {
+            let selection_text = self.selection_text.lock().unwrap();
+
+            selection_text.chunk.items
+                .iter()
+                .zip(self.display_text.chunk.items.iter())
+                .take_while(|(selection_item, display_item)| selection_item == display_item)
+                .count()
+        };
+
         self.selection_text
             .lock().unwrap().deref_mut()
-            .validate(error, current_location, &self.meta_data, data);
+            .validate(error, warnings, current_location, &self.meta_data, data);
 
         // If address errors were found in the selection part of this line they may be repeated
         // in the display part. Since they are parsed from the same line we raise an error for
@@ -88,13 +110,27 @@ impl ValidateContent for InternalChoice {
         // are not essential to report until we know that the selection part is good. Thus,
         // if (but *only* if) we found such an error in the selection part we skip validation in
         // the display part. Otherwise multiple errors about the same part may be raised.
+        //
+        // Similarly, the items which start the display part are copies of those in the
+        // selection part, up until the selection text bracket. Warnings from these items
+        // have already been raised and are skipped.
         if num_address_errors == error.invalid_address_errors.len() {
-            self.display_text
-                .validate(error, current_location, &self.meta_data, data);
+            let meta_data = &self.display_text.meta_data;
+            let mut shared_warnings = Vec::new();
+
+            for (i, item) in self.display_text.chunk.items.iter_mut().enumerate() {
+                let item_warnings = if i < num_shared_items {
+                    &mut shared_warnings
+                } else {
+                    &mut *warnings
+                };
+
+                item.validate(error, item_warnings, current_location, meta_data, data);
+            }
         }
 
         if let Some(ref mut condition) = self.condition {
-            condition.validate(error, current_location, &self.meta_data, data);
+            condition.validate(error, warnings, current_location, &self.meta_data, data);
         }
     }
 }
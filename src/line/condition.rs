@@ -32,11 +32,14 @@
 
 use crate::{
     error::{
-        parse::validate::{ExpressionKind, InvalidVariableExpression, ValidationError},
+        parse::validate::{
+            ExpressionKind, InvalidVariableExpression, ValidationError, ValidationWarning,
+        },
         utils::MetaData,
     },
+    follow::FollowData,
     knot::Address,
-    line::{Expression, Variable},
+    line::{expression::Operand, Expression, Variable},
     process::check_condition,
     story::validate::{ValidateContent, ValidationData},
 };
@@ -159,6 +162,65 @@ impl Condition {
                 })
             })
     }
+
+    /// Evaluate the parts of the condition which do not depend on the story state.
+    ///
+    /// Items which contain addresses to variables or locations in the story are unknown.
+    /// If the condition evaluates to the same value no matter what the unknown items
+    /// evaluate to, that value is returned. Otherwise `None` is returned.
+    pub fn evaluate_constant(&self, data: &FollowData) -> Option<bool> {
+        self.items.iter().fold(
+            inner_eval_constant(&self.root, data),
+            |acc, next_condition| match next_condition {
+                AndOr::And(item) => match (acc, inner_eval_constant(item, data)) {
+                    (Some(false), _) | (_, Some(false)) => Some(false),
+                    (Some(true), Some(true)) => Some(true),
+                    _ => None,
+                },
+                AndOr::Or(item) => match (acc, inner_eval_constant(item, data)) {
+                    (Some(true), _) | (_, Some(true)) => Some(true),
+                    (Some(false), Some(false)) => Some(false),
+                    _ => None,
+                },
+            },
+        )
+    }
+
+    /// Validate the items of the condition.
+    ///
+    /// Does not warn if the condition itself is constant. Nested conditions are validated
+    /// this way, since only the condition as a whole is of interest.
+    fn validate_items(
+        &mut self,
+        error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
+        current_location: &Address,
+        meta_data: &MetaData,
+        data: &ValidationData,
+    ) {
+        let num_errors = error.num_errors();
+
+        self.root
+            .kind
+            .validate(error, warnings, current_location, meta_data, data);
+
+        self.items.iter_mut().for_each(|item| match item {
+            AndOr::And(item) | AndOr::Or(item) => {
+                item.kind
+                    .validate(error, warnings, current_location, meta_data, data)
+            }
+        });
+
+        if num_errors == error.num_errors() {
+            if let Err(err) = check_condition(self, &data.follow_data) {
+                error.variable_errors.push(InvalidVariableExpression {
+                    expression_kind: ExpressionKind::Condition,
+                    kind: err.into(),
+                    meta_data: meta_data.clone(),
+                });
+            }
+        }
+    }
 }
 
 /// Match against and evaluate the items.
@@ -181,6 +243,49 @@ where
     Ok(result)
 }
 
+/// Match against and evaluate the items which do not depend on the story state.
+fn inner_eval_constant(item: &ConditionItem, data: &FollowData) -> Option<bool> {
+    let result = match &item.kind {
+        ConditionKind::True => Some(true),
+        ConditionKind::False => Some(false),
+        ConditionKind::Nested(condition) => condition.evaluate_constant(data),
+        ConditionKind::Single(kind) if !story_condition_has_address(kind) => {
+            let condition = ConditionBuilder::from_kind(&item.kind, false).build();
+            check_condition(&condition, data).ok()
+        }
+        ConditionKind::Single(..) => None,
+    };
+
+    result.map(|value| value != item.negate)
+}
+
+/// Check whether a condition contains an address to a variable or location.
+fn story_condition_has_address(kind: &StoryCondition) -> bool {
+    match kind {
+        StoryCondition::Comparison {
+            lhs_variable,
+            rhs_variable,
+            ..
+        } => expression_has_address(lhs_variable) || expression_has_address(rhs_variable),
+        StoryCondition::IsTrueLike { variable } => variable_is_address(variable),
+    }
+}
+
+/// Check whether an expression contains an address to a variable or location.
+fn expression_has_address(expression: &Expression) -> bool {
+    std::iter::once(&expression.head)
+        .chain(expression.tail.iter().map(|(_, operand)| operand))
+        .any(|operand| match operand {
+            Operand::Nested(expression) => expression_has_address(expression),
+            Operand::Variable(variable) => variable_is_address(variable),
+        })
+}
+
+/// Check whether a variable is an address to another variable or location.
+fn variable_is_address(variable: &Variable) -> bool {
+    matches!(variable, Variable::Address(..))
+}
+
 /// Constructor struct for `Condition`.
 pub struct ConditionBuilder {
     root: ConditionItem,
@@ -235,27 +340,19 @@ impl ValidateContent for Condition {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         meta_data: &MetaData,
         data: &ValidationData,
     ) {
         let num_errors = error.num_errors();
 
-        self.root
-            .kind
-            .validate(error, current_location, meta_data, data);
-
-        self.items.iter_mut().for_each(|item| match item {
-            AndOr::And(item) | AndOr::Or(item) => {
-                item.kind.validate(error, current_location, meta_data, data)
-            }
-        });
+        self.validate_items(error, warnings, current_location, meta_data, data);
 
         if num_errors == error.num_errors() {
-            if let Err(err) = check_condition(self, &data.follow_data) {
-                error.variable_errors.push(InvalidVariableExpression {
-                    expression_kind: ExpressionKind::Condition,
-                    kind: err.into(),
+            if let Some(value) = self.evaluate_constant(&data.follow_data) {
+                warnings.push(ValidationWarning::ConstantCondition {
+                    value,
                     meta_data: meta_data.clone(),
                 });
             }
@@ -267,6 +364,7 @@ impl ValidateContent for ConditionKind {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         meta_data: &MetaData,
         data: &ValidationData,
@@ -274,9 +372,11 @@ impl ValidateContent for ConditionKind {
         match self {
             ConditionKind::True | ConditionKind::False => (),
             ConditionKind::Nested(condition) => {
-                condition.validate(error, current_location, meta_data, data)
+                condition.validate_items(error, warnings, current_location, meta_data, data)
+            }
+            ConditionKind::Single(kind) => {
+                kind.validate(error, warnings, current_location, meta_data, data)
             }
-            ConditionKind::Single(kind) => kind.validate(error, current_location, meta_data, data),
         }
     }
 }
@@ -285,6 +385,7 @@ impl ValidateContent for StoryCondition {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         meta_data: &MetaData,
         data: &ValidationData,
@@ -295,11 +396,11 @@ impl ValidateContent for StoryCondition {
                 ref mut rhs_variable,
                 ..
             } => {
-                lhs_variable.validate(error, current_location, meta_data, data);
-                rhs_variable.validate(error, current_location, meta_data, data);
+                lhs_variable.validate(error, warnings, current_location, meta_data, data);
+                rhs_variable.validate(error, warnings, current_location, meta_data, data);
             }
             StoryCondition::IsTrueLike { variable } => {
-                variable.validate(error, current_location, meta_data, data)
+                variable.validate(error, warnings, current_location, meta_data, data)
             }
         }
     }
@@ -2,7 +2,9 @@
 
 use crate::{
     error::{
-        parse::validate::{ExpressionKind, InvalidVariableExpression, ValidationError},
+        parse::validate::{
+            ExpressionKind, InvalidVariableExpression, ValidationError, ValidationWarning,
+        },
         utils::MetaData,
         InklingError,
     },
@@ -180,17 +182,19 @@ impl ValidateContent for Expression {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         meta_data: &MetaData,
         data: &ValidationData,
     ) {
         let num_errors = error.num_errors();
 
-        self.head.validate(error, current_location, meta_data, data);
+        self.head
+            .validate(error, warnings, current_location, meta_data, data);
 
-        self.tail
-            .iter_mut()
-            .for_each(|(_, operand)| operand.validate(error, current_location, meta_data, data));
+        self.tail.iter_mut().for_each(|(_, operand)| {
+            operand.validate(error, warnings, current_location, meta_data, data)
+        });
 
         if num_errors == error.num_errors() {
             if let Err(err) = evaluate_expression(self, &data.follow_data) {
@@ -208,16 +212,17 @@ impl ValidateContent for Operand {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         meta_data: &MetaData,
         data: &ValidationData,
     ) {
         match self {
             Operand::Nested(ref mut expression) => {
-                expression.validate(error, current_location, meta_data, data)
+                expression.validate(error, warnings, current_location, meta_data, data)
             }
             Operand::Variable(ref mut variable) => {
-                variable.validate(error, current_location, meta_data, data)
+                variable.validate(error, warnings, current_location, meta_data, data)
             }
         }
     }
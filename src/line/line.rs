@@ -1,7 +1,10 @@
 //! Structures for representing a single, whole line of `Ink` content.
 
 use crate::{
-    error::{parse::validate::ValidationError, utils::MetaData},
+    error::{
+        parse::validate::{ValidationError, ValidationWarning},
+        utils::MetaData,
+    },
     knot::Address,
     line::{Alternative, Condition, Expression},
     story::validate::{ValidateContent, ValidationData},
@@ -109,6 +112,11 @@ impl InternalLine {
         buffer
     }
 
+    /// Reset all alternatives in the line to their initial state.
+    pub fn reset(&mut self) {
+        self.chunk.reset();
+    }
+
     #[cfg(test)]
     pub fn from_string(line: &str) -> Self {
         use builders::LineChunkBuilder;
@@ -118,16 +126,31 @@ impl InternalLine {
     }
 }
 
+impl LineChunk {
+    /// Reset all alternatives in the chunk, including the `else` content, to their initial state.
+    pub fn reset(&mut self) {
+        self.items
+            .iter_mut()
+            .chain(self.else_items.iter_mut())
+            .for_each(|item| match item {
+                Content::Alternative(alternative) => alternative.reset(),
+                Content::Nested(chunk) => chunk.reset(),
+                _ => (),
+            });
+    }
+}
+
 impl ValidateContent for InternalLine {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         _: &MetaData,
         data: &ValidationData,
     ) {
         self.chunk
-            .validate(error, current_location, &self.meta_data, data);
+            .validate(error, warnings, current_location, &self.meta_data, data);
     }
 }
 
@@ -135,18 +158,19 @@ impl ValidateContent for LineChunk {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         meta_data: &MetaData,
         data: &ValidationData,
     ) {
         if let Some(condition) = self.condition.as_mut() {
-            condition.validate(error, current_location, meta_data, data);
+            condition.validate(error, warnings, current_location, meta_data, data);
         }
 
         self.items
             .iter_mut()
             .chain(self.else_items.iter_mut())
-            .for_each(|item| item.validate(error, current_location, meta_data, data));
+            .for_each(|item| item.validate(error, warnings, current_location, meta_data, data));
     }
 }
 
@@ -154,20 +178,25 @@ impl ValidateContent for Content {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         meta_data: &MetaData,
         data: &ValidationData,
     ) {
         match self {
             Content::Alternative(alternative) => {
-                alternative.validate(error, current_location, meta_data, data)
+                alternative.validate(error, warnings, current_location, meta_data, data)
+            }
+            Content::Divert(address) => {
+                address.validate(error, warnings, current_location, meta_data, data)
             }
-            Content::Divert(address) => address.validate(error, current_location, meta_data, data),
             Content::Empty | Content::Text(..) => (),
             Content::Expression(expression) => {
-                expression.validate(error, current_location, meta_data, data)
+                expression.validate(error, warnings, current_location, meta_data, data)
+            }
+            Content::Nested(chunk) => {
+                chunk.validate(error, warnings, current_location, meta_data, data)
             }
-            Content::Nested(chunk) => chunk.validate(error, current_location, meta_data, data),
         }
     }
 }
@@ -33,5 +33,5 @@ pub(crate) use expression::{evaluate_expression, Expression};
 #[cfg(test)]
 pub(crate) use line::builders::LineChunkBuilder;
 pub(crate) use line::{Content, InternalLine, LineChunk};
-pub(crate) use parse::{parse_line, parse_variable, ParsedLineKind};
-pub use variable::Variable;
+pub(crate) use parse::{parse_line, parse_variable, unescape_tag, ParsedLineKind};
+pub use variable::{Variable, VariableType};
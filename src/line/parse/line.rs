@@ -1,7 +1,7 @@
 //! Parse `InternalLine` and `LineChunk` objects.
 
 use crate::{
-    consts::{DIVERT_MARKER, GLUE_MARKER, TAG_MARKER},
+    consts::{DIVERT_MARKER, GLUE_MARKER, TAG_ESCAPED_CHARS, TAG_MARKER},
     error::{parse::line::LineErrorKind, utils::MetaData},
     knot::Address,
     line::{
@@ -155,18 +155,89 @@ fn parse_line_glue(line: &mut String, has_divert: bool) -> (bool, bool) {
 }
 
 /// Split any found tags off the given line and return them separately.
+///
+/// A backslash '\\' escapes the character after it. Escaped tag markers do not start or
+/// separate tags. In both the line text and the tags, escaped tag markers and backslashes
+/// are replaced by the escaped character. Escaped curly braces are also replaced in the
+/// tags, but are left as they are in the line text where they are handled when parsing
+/// its content.
 fn parse_tags(line: &mut String) -> Vec<String> {
-    match line.find(TAG_MARKER) {
+    let tags = match find_unescaped(line, TAG_MARKER) {
         Some(i) => {
             let part = line.split_off(i);
+            let mut rest = part.get(TAG_MARKER.len_utf8()..).unwrap();
+            let mut tags = Vec::new();
+
+            while let Some(j) = find_unescaped(rest, TAG_MARKER) {
+                tags.push(rest.get(..j).unwrap());
+                rest = rest.get(j + TAG_MARKER.len_utf8()..).unwrap();
+            }
+
+            tags.push(rest);
 
-            part.trim_matches(TAG_MARKER)
-                .split(TAG_MARKER)
-                .map(|tag| tag.trim().to_string())
-                .collect::<Vec<_>>()
+            while tags.len() > 1 && tags.last().map(|tag| tag.is_empty()).unwrap_or(false) {
+                tags.pop();
+            }
+
+            tags.into_iter()
+                .map(|tag| unescape_tag(tag).trim().to_string())
+                .collect()
         }
         None => Vec::new(),
+    };
+
+    *line = remove_escapes(line, &[TAG_MARKER, '\\']);
+
+    tags
+}
+
+/// Replace escaped characters in tag text with the characters themselves.
+///
+/// See [`TAG_ESCAPED_CHARS`][crate::consts::TAG_ESCAPED_CHARS] for which characters
+/// can be escaped.
+pub fn unescape_tag(tag: &str) -> String {
+    remove_escapes(tag, TAG_ESCAPED_CHARS)
+}
+
+/// Find the byte index of the first occurrence of a character which is not escaped.
+fn find_unescaped(line: &str, marker: char) -> Option<usize> {
+    let mut escaped = false;
+
+    for (i, c) in line.char_indices() {
+        if escaped {
+            escaped = false;
+        } else if c == '\\' {
+            escaped = true;
+        } else if c == marker {
+            return Some(i);
+        }
+    }
+
+    None
+}
+
+/// Replace the given characters when escaped by a backslash with the characters themselves.
+///
+/// Other escaped characters are kept as they are, along with their backslash.
+fn remove_escapes(text: &str, escaped_chars: &[char]) -> String {
+    let mut unescaped = String::with_capacity(text.len());
+    let mut chars = text.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('\\', Some(&next)) => {
+                if !escaped_chars.contains(&next) {
+                    unescaped.push(c);
+                }
+
+                unescaped.push(next);
+                chars.next();
+            }
+            _ => unescaped.push(c),
+        }
     }
+
+    unescaped
 }
 
 /// Split diverts off the given line and return it separately if found.
@@ -440,6 +511,60 @@ mod tests {
         );
     }
 
+    #[test]
+    fn escaped_tag_markers_are_kept_as_text_in_tags() {
+        let line = parse_internal_line("Hello, World! # issue \\#5 # tag", &().into()).unwrap();
+
+        assert_eq!(&line.tags, &["issue #5".to_string(), "tag".to_string()]);
+    }
+
+    #[test]
+    fn escaped_braces_and_backslashes_are_kept_as_text_in_tags() {
+        let line = parse_internal_line("Hello! # \\{name\\} # back\\\\slash", &().into()).unwrap();
+
+        assert_eq!(
+            &line.tags,
+            &["{name}".to_string(), "back\\slash".to_string()]
+        );
+    }
+
+    #[test]
+    fn escaped_backslash_before_tag_marker_does_not_escape_the_marker() {
+        let line = parse_internal_line("Hello! # one\\\\# two", &().into()).unwrap();
+
+        assert_eq!(&line.tags, &["one\\".to_string(), "two".to_string()]);
+    }
+
+    #[test]
+    fn escaped_tag_marker_in_line_text_does_not_start_tags() {
+        let line = parse_internal_line("Hello, \\#World! # tag", &().into()).unwrap();
+
+        assert_eq!(&line.tags, &["tag".to_string()]);
+        assert_eq!(
+            line.chunk.items[0],
+            Content::Text("Hello, #World! ".to_string())
+        );
+    }
+
+    #[test]
+    fn escaped_backslash_in_line_text_is_replaced_by_a_backslash() {
+        let line = parse_internal_line("C:\\\\# tag", &().into()).unwrap();
+
+        assert_eq!(&line.tags, &["tag".to_string()]);
+        assert_eq!(line.chunk.items[0], Content::Text("C:\\".to_string()));
+    }
+
+    #[test]
+    fn escaped_tag_marker_in_line_text_without_tags_has_backslash_removed() {
+        let line = parse_internal_line("Issue \\#5 is \\{fixed\\}.", &().into()).unwrap();
+
+        assert!(line.tags.is_empty());
+        assert_eq!(
+            line.chunk.items[0],
+            Content::Text("Issue #5 is \\{fixed\\}.".to_string())
+        );
+    }
+
     #[test]
     fn parse_embraced_line_as_alternative() {
         match parse_embraced_line("One | Two").unwrap() {
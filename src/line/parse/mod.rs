@@ -28,7 +28,7 @@ pub(self) use expression::parse_expression;
 pub(self) use gather::parse_gather;
 pub use kind::{parse_line, ParsedLineKind};
 pub(self) use kind::{parse_markers_and_text, split_at_divert_marker};
-pub use line::{parse_chunk, parse_internal_line, validate_address};
+pub use line::{parse_chunk, parse_internal_line, unescape_tag, validate_address};
 pub(self) use utils::{
     split_line_at_separator_braces, split_line_at_separator_parenthesis,
     split_line_into_groups_braces, LinePart,
@@ -2,7 +2,7 @@
 
 use crate::{
     error::{
-        parse::validate::ValidationError,
+        parse::validate::{ValidationError, ValidationWarning},
         utils::MetaData,
         variable::{VariableError, VariableErrorKind},
         InklingError, InternalError,
@@ -86,6 +86,27 @@ pub enum Variable {
     String(String),
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde_support", derive(Deserialize, Serialize))]
+/// Type of a [`Variable`][crate::line::Variable], without its value.
+///
+/// Mirrors the variants of `Variable`. Useful for callers that need to know how
+/// to present or edit a variable without matching on its value.
+pub enum VariableType {
+    /// Address to a stitch or other variable.
+    Address,
+    /// True or false.
+    Bool,
+    /// Divert to another address.
+    DivertTarget,
+    /// Decimal number.
+    Float,
+    /// Integer number.
+    Int,
+    /// Text string.
+    String,
+}
+
 impl Variable {
     /// Return a string representation of the variable.
     pub(crate) fn to_string(&self, data: &FollowData) -> Result<String, InklingError> {
@@ -642,6 +663,18 @@ impl Variable {
         }
     }
 
+    /// Get the type of the variable.
+    pub(crate) fn get_type(&self) -> VariableType {
+        match &self {
+            Variable::Address(..) => VariableType::Address,
+            Variable::Bool(..) => VariableType::Bool,
+            Variable::Divert(..) => VariableType::DivertTarget,
+            Variable::Float(..) => VariableType::Float,
+            Variable::Int(..) => VariableType::Int,
+            Variable::String(..) => VariableType::String,
+        }
+    }
+
     /// Get string representation of the variant.
     pub(crate) fn variant_string(&self) -> &str {
         match &self {
@@ -698,13 +731,14 @@ impl ValidateContent for Variable {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         meta_data: &MetaData,
         data: &ValidationData,
     ) {
         match self {
             Variable::Address(address) | Variable::Divert(address) => {
-                address.validate(error, current_location, meta_data, data);
+                address.validate(error, warnings, current_location, meta_data, data);
             }
             Variable::Bool(..) | Variable::Float(..) | Variable::Int(..) | Variable::String(..) => {
                 ()
@@ -929,6 +963,27 @@ mod tests {
         assert!(Variable::Divert(address).to_string(&data).is_err());
     }
 
+    #[test]
+    fn variable_type_corresponds_to_the_variant() {
+        let address = Address::Raw("knot".to_string());
+
+        assert_eq!(
+            Variable::Address(address.clone()).get_type(),
+            VariableType::Address
+        );
+        assert_eq!(Variable::Bool(true).get_type(), VariableType::Bool);
+        assert_eq!(
+            Variable::Divert(address).get_type(),
+            VariableType::DivertTarget
+        );
+        assert_eq!(Variable::Float(1.0).get_type(), VariableType::Float);
+        assert_eq!(Variable::Int(1).get_type(), VariableType::Int);
+        assert_eq!(
+            Variable::String("".to_string()).get_type(),
+            VariableType::String
+        );
+    }
+
     #[test]
     fn assign_variable_value_updates_inner_value() {
         let mut variable = Variable::Int(5);
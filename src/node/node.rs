@@ -1,7 +1,10 @@
 //! Node tree structure for branching content.
 
 use crate::{
-    error::{parse::validate::ValidationError, utils::MetaData},
+    error::{
+        parse::validate::{ValidationError, ValidationWarning},
+        utils::MetaData,
+    },
     knot::Address,
     line::{InternalChoice, InternalLine},
     story::validate::{ValidateContent, ValidationData},
@@ -62,17 +65,46 @@ impl NodeItem {
     }
 }
 
+impl RootNode {
+    /// Reset all content in the node to the state it had before the story was followed.
+    pub fn reset(&mut self) {
+        self.items.iter_mut().for_each(|item| item.reset());
+    }
+}
+
+impl Branch {
+    /// Reset the number of visits and all content in the branch to their initial state.
+    pub fn reset(&mut self) {
+        self.num_visited = 0;
+        self.choice.reset();
+        self.items.iter_mut().for_each(|item| item.reset());
+    }
+}
+
+impl NodeItem {
+    /// Reset the line or all branches in the item to their initial state.
+    pub fn reset(&mut self) {
+        match self {
+            NodeItem::BranchingPoint(branches) => {
+                branches.iter_mut().for_each(|branch| branch.reset())
+            }
+            NodeItem::Line(line) => line.reset(),
+        }
+    }
+}
+
 impl ValidateContent for RootNode {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         meta_data: &MetaData,
         data: &ValidationData,
     ) {
         self.items
             .iter_mut()
-            .for_each(|item| item.validate(error, current_location, meta_data, data))
+            .for_each(|item| item.validate(error, warnings, current_location, meta_data, data))
     }
 }
 
@@ -80,6 +112,7 @@ impl ValidateContent for Branch {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         meta_data: &MetaData,
         data: &ValidationData,
@@ -87,20 +120,27 @@ impl ValidateContent for Branch {
         let num_errors = error.num_errors();
 
         self.choice
-            .validate(error, current_location, meta_data, data);
+            .validate(error, warnings, current_location, meta_data, data);
 
         // The first line of these items is the selection text from the choice. If we found
         // errors when evaluating that we do not want to add copies of them:
         // instead, we skip it if (but *only* if) an error was found. If no errors were found
-        // we have to ensure that addresses are validated in it.
+        // we have to ensure that addresses are validated in it. Any warnings from it have
+        // already been raised for the choice, so they are always skipped.
         if let Some((choice_display_line, items)) = self.items.split_first_mut() {
             if num_errors == error.num_errors() {
-                choice_display_line.validate(error, current_location, meta_data, data);
+                choice_display_line.validate(
+                    error,
+                    &mut Vec::new(),
+                    current_location,
+                    meta_data,
+                    data,
+                );
             }
 
             items
                 .iter_mut()
-                .for_each(|item| item.validate(error, current_location, meta_data, data));
+                .for_each(|item| item.validate(error, warnings, current_location, meta_data, data));
         }
     }
 }
@@ -109,6 +149,7 @@ impl ValidateContent for NodeItem {
     fn validate(
         &mut self,
         error: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         meta_data: &MetaData,
         data: &ValidationData,
@@ -116,8 +157,10 @@ impl ValidateContent for NodeItem {
         match self {
             NodeItem::BranchingPoint(branches) => branches
                 .iter_mut()
-                .for_each(|item| item.validate(error, current_location, meta_data, data)),
-            NodeItem::Line(line) => line.validate(error, current_location, meta_data, data),
+                .for_each(|item| item.validate(error, warnings, current_location, meta_data, data)),
+            NodeItem::Line(line) => {
+                line.validate(error, warnings, current_location, meta_data, data)
+            }
         };
     }
 }
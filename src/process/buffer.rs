@@ -7,10 +7,7 @@ use crate::{
 
 /// Process internal lines to a user-ready state.
 pub fn process_buffer(into_buffer: &mut LineBuffer, from_buffer: LineDataBuffer) {
-    let mut iter = from_buffer
-        .into_iter()
-        .filter(|line| !line.text.trim().is_empty())
-        .peekable();
+    let mut iter = remove_empty_lines(from_buffer).into_iter().peekable();
 
     while let Some(mut line) = iter.next() {
         let (glue, whitespace) = check_for_whitespace_and_glue(&line, iter.peek());
@@ -25,6 +22,32 @@ pub fn process_buffer(into_buffer: &mut LineBuffer, from_buffer: LineDataBuffer)
     }
 }
 
+/// Remove lines without text content from the buffer.
+///
+/// An empty line can still begin with glue, for example the output of a fallback choice
+/// which is nothing but glue and a divert (`*   <> -> west`). The glue is then moved
+/// to the previous line with content in the buffer, to join it with the next line
+/// that remains.
+///
+/// Only lines in this buffer can be joined. Lines from an earlier call to `resume` have
+/// already been returned to the caller, so the glue of a selected choice's empty output
+/// (`*   [Go west] <> -> west`) has no previous line to bind and is dropped.
+fn remove_empty_lines(buffer: LineDataBuffer) -> LineDataBuffer {
+    let mut lines: LineDataBuffer = Vec::new();
+
+    for line in buffer {
+        if !line.text.trim().is_empty() {
+            lines.push(line);
+        } else if line.glue_begin {
+            if let Some(prev_line) = lines.last_mut() {
+                prev_line.glue_end = true;
+            }
+        }
+    }
+
+    lines
+}
+
 /// Check whether the line is glued to the next and if so whether it ends with a blank space.
 fn check_for_whitespace_and_glue(line: &LineText, next_line: Option<&LineText>) -> (bool, bool) {
     let glue = next_line
@@ -170,6 +193,44 @@ mod tests {
         assert!(processed[1].text.ends_with('\n'));
     }
 
+    #[test]
+    fn processing_line_buffer_keeps_glue_from_empty_lines_which_begin_with_glue() {
+        let text = "Mr. and Mrs. Doubtfire";
+
+        let buffer = vec![
+            LineTextBuilder::from_string(text).build(),
+            LineTextBuilder::from_string("")
+                .with_glue_begin()
+                .with_glue_end()
+                .build(),
+            LineTextBuilder::from_string(text).build(),
+        ];
+
+        let mut processed = Vec::new();
+        process_buffer(&mut processed, buffer);
+
+        assert_eq!(processed.len(), 2);
+        assert!(!processed[0].text.ends_with('\n'));
+        assert!(processed[1].text.ends_with('\n'));
+    }
+
+    #[test]
+    fn processing_line_buffer_does_not_glue_lines_around_empty_lines_with_only_glue_end() {
+        let text = "Mr. and Mrs. Doubtfire";
+
+        let buffer = vec![
+            LineTextBuilder::from_string(text).build(),
+            LineTextBuilder::from_string("").with_glue_end().build(),
+            LineTextBuilder::from_string(text).build(),
+        ];
+
+        let mut processed = Vec::new();
+        process_buffer(&mut processed, buffer);
+
+        assert!(processed[0].text.ends_with('\n'));
+        assert!(processed[1].text.ends_with('\n'));
+    }
+
     #[test]
     fn processing_line_buffer_sets_newline_on_last_line_regardless_of_glue() {
         let line = LineTextBuilder::from_string("Mr. and Mrs. Doubtfire")
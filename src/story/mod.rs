@@ -19,5 +19,5 @@ pub(crate) mod validate;
 
 pub use parse::read_story_content_from_string;
 pub use story::{read_story_from_string, Story};
-pub use types::{Choice, Line, LineBuffer, Prompt};
+pub use types::{Choice, ChoiceNumbering, Line, LineBuffer, Prompt, TranscriptEntry};
 pub use utils::copy_lines_into_string;
@@ -20,7 +20,7 @@ use crate::{
         ReadError,
     },
     knot::{parse_stitch_from_lines, read_knot_name, read_stitch_name, Knot, KnotSet, Stitch},
-    line::{parse_variable, Variable},
+    line::{parse_variable, unescape_tag, Variable},
     story::types::{VariableInfo, VariableSet},
 };
 
@@ -358,7 +358,7 @@ fn get_knot_tags(lines: &mut Vec<(&str, MetaData)>) -> Vec<String> {
             .drain(..i)
             .map(|(line, _)| line.trim())
             .filter(|line| !line.is_empty())
-            .map(|line| line.trim_start_matches("#").trim_start().to_string())
+            .map(|line| unescape_tag(line.trim_start_matches("#").trim_start()))
             .collect()
     } else {
         Vec::new()
@@ -463,7 +463,7 @@ fn parse_global_tags(lines: &[(&str, MetaData)]) -> Vec<String> {
         .iter()
         .map(|(line, _)| line.trim())
         .filter(|line| line.starts_with(TAG_MARKER))
-        .map(|line| line.get(1..).unwrap().trim().to_string())
+        .map(|line| unescape_tag(line.get(1..).unwrap().trim()))
         .collect()
 }
 
@@ -649,6 +649,16 @@ pub mod tests {
         assert_eq!(&parse_global_tags(&enumerate(lines)), &["Tag", "Tag two"]);
     }
 
+    #[test]
+    fn escaped_characters_are_kept_as_text_in_global_tags() {
+        let lines = &["# issue \\#5", "# \\{name\\} back\\\\slash"];
+
+        assert_eq!(
+            &parse_global_tags(&enumerate(lines)),
+            &["issue #5", "{name} back\\slash"]
+        );
+    }
+
     #[test]
     fn parse_variables_from_metadata() {
         let lines = &[
@@ -975,6 +985,17 @@ Second line.
         assert_eq!(&knot.tags, &["Tag one".to_string(), "Tag two".to_string()]);
     }
 
+    #[test]
+    fn escaped_characters_are_kept_as_text_in_knot_tags() {
+        let lines = enumerate(&["== knot_name", "# issue \\#5", "# back\\\\slash", "Line 1"]);
+
+        let (_, knot) = get_knot_from_lines(lines).unwrap();
+        assert_eq!(
+            &knot.tags,
+            &["issue #5".to_string(), "back\\slash".to_string()]
+        );
+    }
+
     #[test]
     fn knot_tags_ignore_empty_lines() {
         let lines = enumerate(&["== knot_name", "", "# Tag one", "", "# Tag two", "Line 1"]);
@@ -1,15 +1,20 @@
 //! Structures which contain parsed `Ink` stories and content presented to the user.
 
 use crate::{
-    consts::ROOT_KNOT_NAME,
-    error::{runtime::internal::StackError, InklingError, ReadError},
+    consts::{DONE_KNOT, END_KNOT, ROOT_KNOT_NAME},
+    error::{
+        parse::validate::ValidationWarning, runtime::internal::StackError, InklingError, ReadError,
+    },
     follow::{ChoiceInfo, EncounteredEvent, FollowData, LineDataBuffer},
-    knot::{get_empty_knot_counts, get_mut_stitch, get_num_visited, Address, KnotSet},
-    line::Variable,
+    knot::{
+        get_empty_knot_counts, get_mut_stitch, get_num_visited, set_num_visited, Address,
+        AddressKind, KnotSet,
+    },
+    line::{Variable, VariableType},
     process::{get_fallback_choices, prepare_choices_for_user, process_buffer},
     story::{
         parse::read_story_content_from_string,
-        types::{Choice, LineBuffer, Prompt},
+        types::{Choice, ChoiceNumbering, LineBuffer, Prompt, TranscriptEntry, VariableSet},
         validate::validate_story_content,
     },
 };
@@ -27,14 +32,43 @@ pub struct Story {
     stack: Vec<Address>,
     /// Internal data for the story.
     data: FollowData,
+    /// Global variables with the values they were given in the story file.
+    ///
+    /// Empty for stories saved before these were recorded.
+    #[cfg_attr(feature = "serde_support", serde(default))]
+    initial_variables: VariableSet,
     /// Global tags associated with the story.
     tags: Vec<String>,
+    /// Warnings about the story content that were found when reading it.
+    #[cfg_attr(feature = "serde_support", serde(default))]
+    warnings: Vec<ValidationWarning>,
     /// Set of last choices presented to the user.
     last_choices: Option<Vec<Choice>>,
     /// Choice that has been set to resume the story with.
     selected_choice: Option<usize>,
     /// Whether or not the story has been started.
     in_progress: bool,
+    /// Style of labels to prefix presented choices with.
+    #[cfg_attr(feature = "serde_support", serde(default))]
+    choice_numbering: ChoiceNumbering,
+    /// Whether or not produced lines and made choices are recorded in the transcript.
+    #[cfg_attr(feature = "serde_support", serde(default))]
+    transcript_enabled: bool,
+    /// Whether or not the transcript is cleared when the story is restarted.
+    #[cfg_attr(
+        feature = "serde_support",
+        serde(default = "default_clear_transcript_on_restart")
+    )]
+    clear_transcript_on_restart: bool,
+    /// Recorded lines and choices of the playthrough.
+    #[cfg_attr(feature = "serde_support", serde(default))]
+    transcript: Vec<TranscriptEntry>,
+}
+
+#[cfg(feature = "serde_support")]
+/// Default for `clear_transcript_on_restart` in stories saved before it was added.
+fn default_clear_transcript_on_restart() -> bool {
+    true
 }
 
 impl Story {
@@ -225,7 +259,7 @@ impl Story {
     /// *   [`MadeChoiceWithoutChoice`][crate::error::InklingError::MadeChoiceWithoutChoice]:
     ///     if the story is not currently at a branching point.
     pub fn make_choice(&mut self, selection: usize) -> Result<(), InklingError> {
-        let index = self
+        let choice = self
             .last_choices
             .as_ref()
             .ok_or(InklingError::MadeChoiceWithoutChoice)
@@ -236,12 +270,16 @@ impl Story {
                         selection,
                         presented_choices: last_choices.clone(),
                     })
-                    .map(|choice| choice.index)
+                    .cloned()
             })?;
 
-        self.selected_choice.replace(index);
+        self.selected_choice.replace(choice.index);
         self.last_choices = None;
 
+        if self.transcript_enabled {
+            self.transcript.push(TranscriptEntry::Choice(choice));
+        }
+
         Ok(())
     }
 
@@ -362,6 +400,46 @@ impl Story {
             })
     }
 
+    /// Get the names of all knots which are marked with a tag.
+    ///
+    /// Tags of the form `key: value` are matched by their key, as well as by the full tag.
+    /// The names are returned in sorted order.
+    ///
+    /// # Example
+    /// ```
+    /// # use inkling::read_story_from_string;
+    /// let content = "\
+    /// === duel ===
+    /// ## combat
+    /// Steel rang against steel.
+    ///
+    /// === ambush ===
+    /// ## combat: hard
+    /// Arrows rained from the tree line.
+    ///
+    /// === market ===
+    /// ## trade
+    /// The stalls were full of spices.
+    /// ";
+    ///
+    /// let story = read_story_from_string(content).unwrap();
+    /// let knots = story.knots_with_tag("combat");
+    ///
+    /// assert_eq!(&knots, &["ambush", "duel"]);
+    /// ```
+    pub fn knots_with_tag(&self, tag: &str) -> Vec<String> {
+        let mut knots = self
+            .knots
+            .iter()
+            .filter(|(_, knot)| knot.tags.iter().any(|knot_tag| tag_matches(knot_tag, tag)))
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+
+        knots.sort();
+
+        knots
+    }
+
     /// Get the number of times a knot or stitch has been visited so far.
     ///
     /// # Examples
@@ -396,6 +474,54 @@ impl Story {
         get_num_visited(&address, &self.data).map_err(|err| err.into())
     }
 
+    /// Set the number of times a knot or stitch has been visited so far.
+    ///
+    /// Can be used to restore visit counts from a saved game, so that content which
+    /// depends on them is presented correctly without replaying the story.
+    ///
+    /// Only the visit count is changed. Alternative sequences and choices which have
+    /// already been followed keep their current state, independent of this number.
+    ///
+    /// # Examples
+    /// ```
+    /// # use inkling::read_story_from_string;
+    /// let content = "\
+    /// -> depths
+    /// === depths ===
+    /// {depths > 2: You know these tunnels by heart.|You enter the dungeon.}
+    /// ";
+    ///
+    /// let mut story = read_story_from_string(content).unwrap();
+    /// let mut line_buffer = Vec::new();
+    ///
+    /// story.set_visit_count("depths", None, 2).unwrap();
+    ///
+    /// story.start().unwrap();
+    /// story.resume(&mut line_buffer).unwrap();
+    ///
+    /// assert_eq!(&line_buffer[0].text, "You know these tunnels by heart.\n");
+    /// assert_eq!(story.get_num_visited("depths", None).unwrap(), 3);
+    /// ```
+    ///
+    /// # Errors
+    /// *   [`InvalidAddress`][crate::error::InklingError::InvalidAddress]: if the knot
+    ///     or stitch name does not specify an existing location in the story.
+    pub fn set_visit_count(
+        &mut self,
+        knot: &str,
+        stitch: Option<&str>,
+        count: u32,
+    ) -> Result<(), InklingError> {
+        let address = Address::from_parts(knot, stitch, &self.knots).map_err(|_| {
+            InklingError::InvalidAddress {
+                knot: knot.to_string(),
+                stitch: stitch.map(|s| s.to_string()),
+            }
+        })?;
+
+        set_num_visited(&address, count, &mut self.data).map_err(|err| err.into())
+    }
+
     /// Retrieve the global tags associated with the story.
     ///
     /// # Example
@@ -416,6 +542,33 @@ impl Story {
         self.tags.clone()
     }
 
+    /// Retrieve the warnings about the story content that were found when reading it.
+    ///
+    /// Warnings are raised for content which is valid but likely a mistake, for example
+    /// conditions which are always true or false.
+    ///
+    /// # Examples
+    /// ```
+    /// # use inkling::{error::parse::validate::ValidationWarning, read_story_from_string};
+    /// let content = "\
+    /// The shop was empty.
+    /// *   {false} Buy a sandwich.
+    /// *   Leave.
+    /// ";
+    ///
+    /// let story = read_story_from_string(content).unwrap();
+    ///
+    /// match &story.get_warnings()[0] {
+    ///     ValidationWarning::ConstantCondition { value, meta_data } => {
+    ///         assert!(!value);
+    ///         assert_eq!(meta_data.line_index, 1);
+    ///     }
+    /// }
+    /// ```
+    pub fn get_warnings(&self) -> Vec<ValidationWarning> {
+        self.warnings.clone()
+    }
+
     /// Retrieve the value of a global variable.
     ///
     /// # Examples
@@ -444,6 +597,37 @@ impl Story {
             })
     }
 
+    /// Retrieve the type of a global variable.
+    ///
+    /// The type is that of the variable's current value.
+    ///
+    /// # Examples
+    /// ```
+    /// # use inkling::{read_story_from_string, VariableType};
+    /// let content = "\
+    /// VAR books_in_library = 3
+    /// VAR title = \"A Momentuous Spectacle\"
+    /// ";
+    ///
+    /// let story = read_story_from_string(content).unwrap();
+    ///
+    /// assert_eq!(story.variable_type("books_in_library").unwrap(), VariableType::Int);
+    /// assert_eq!(story.variable_type("title").unwrap(), VariableType::String);
+    /// ```
+    ///
+    /// # Errors
+    /// *   [`InvalidVariable`][crate::error::InklingError::InvalidVariable]: if the name
+    ///     does not refer to a global variable that exists in the story.
+    pub fn variable_type(&self, name: &str) -> Result<VariableType, InklingError> {
+        self.data
+            .variables
+            .get(name)
+            .map(|variable_info| variable_info.variable.get_type())
+            .ok_or(InklingError::InvalidVariable {
+                name: name.to_string(),
+            })
+    }
+
     /// Retrieve the value of a global variable in its string representation.
     ///
     /// Will return an error if the variable contains a `Divert` value, which cannot be
@@ -473,6 +657,96 @@ impl Story {
             .and_then(|variable_info| variable_info.variable.to_string(&self.data))
     }
 
+    /// Set a style of labels to prefix the text of presented choices with.
+    ///
+    /// This is a convenience for simple text front-ends. The label is set before the
+    /// processed choice text, separated by a single space, and numbers the choices
+    /// in the order they are presented. Tags of the choices are not affected.
+    /// Use `ChoiceNumbering::None` (the default) to present the text as-is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use inkling::{read_story_from_string, ChoiceNumbering};
+    /// let content = "\
+    /// The ferry was leaving in an hour.
+    /// *   Wait at the pier.
+    /// *   Buy a ticket.
+    /// ";
+    ///
+    /// let mut story = read_story_from_string(content).unwrap();
+    /// let mut line_buffer = Vec::new();
+    ///
+    /// story.set_choice_numbering(ChoiceNumbering::Numbers);
+    /// story.start().unwrap();
+    ///
+    /// let choices = story.resume(&mut line_buffer).unwrap().get_choices().unwrap();
+    ///
+    /// assert_eq!(&choices[0].text, "1. Wait at the pier.");
+    /// assert_eq!(&choices[1].text, "2. Buy a ticket.");
+    /// ```
+    pub fn set_choice_numbering(&mut self, numbering: ChoiceNumbering) {
+        self.choice_numbering = numbering;
+    }
+
+    /// Set whether or not to record a transcript of the playthrough.
+    ///
+    /// While enabled, every line that is produced by [`resume`][crate::story::Story::resume()]
+    /// and every choice that is made with [`make_choice`][crate::story::Story::make_choice()]
+    /// is added to the transcript, in order. Choices are recorded as they were presented.
+    /// Disabling the transcript stops the recording but keeps what has been recorded.
+    ///
+    /// The transcript is cleared when the story is [restarted][crate::story::Story::restart()],
+    /// unless this is turned off with
+    /// [`set_clear_transcript_on_restart`][crate::story::Story::set_clear_transcript_on_restart()].
+    ///
+    /// # Examples
+    /// ```
+    /// # use inkling::{read_story_from_string, TranscriptEntry};
+    /// let content = "\
+    /// The ferry was leaving in an hour.
+    /// *   Wait at the pier.
+    /// *   Buy a ticket.
+    /// ";
+    ///
+    /// let mut story = read_story_from_string(content).unwrap();
+    /// let mut line_buffer = Vec::new();
+    ///
+    /// story.enable_transcript(true);
+    ///
+    /// story.start().unwrap();
+    /// story.resume(&mut line_buffer).unwrap();
+    /// story.make_choice(1).unwrap();
+    ///
+    /// let transcript = story.transcript();
+    ///
+    /// match (&transcript[0], &transcript[1]) {
+    ///     (TranscriptEntry::Line(line), TranscriptEntry::Choice(choice)) => {
+    ///         assert_eq!(&line.text, "The ferry was leaving in an hour.\n");
+    ///         assert_eq!(&choice.text, "Buy a ticket.");
+    ///     }
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn enable_transcript(&mut self, enable: bool) {
+        self.transcript_enabled = enable;
+    }
+
+    /// Set whether or not the transcript is cleared when the story is restarted.
+    ///
+    /// If not cleared, the transcript of the new playthrough is appended to the old.
+    /// The transcript is cleared by default.
+    pub fn set_clear_transcript_on_restart(&mut self, clear: bool) {
+        self.clear_transcript_on_restart = clear;
+    }
+
+    /// Retrieve the recorded transcript of the playthrough.
+    ///
+    /// Is empty unless recording has been enabled with
+    /// [`enable_transcript`][crate::story::Story::enable_transcript()].
+    pub fn transcript(&self) -> Vec<TranscriptEntry> {
+        self.transcript.clone()
+    }
+
     /// Set the value of an existing global variable.
     ///
     /// New variables cannot be created using this method. They have to be defined in the Ink
@@ -549,6 +823,148 @@ impl Story {
             })
     }
 
+    /// Restart the story from the beginning.
+    ///
+    /// All knot and stitch visit counts are set to zero, alternative sequences and
+    /// non-sticky choices are reset and the story is moved back to the root knot.
+    /// The values of global variables are kept as they are.
+    ///
+    /// The story has to be started again with [`start`][crate::story::Story::start()]
+    /// before the text flow can be resumed.
+    ///
+    /// # Examples
+    /// ```
+    /// # use inkling::read_story_from_string;
+    /// let content = "\
+    /// {Once|Twice|Many times} upon a time.
+    /// ";
+    ///
+    /// let mut story = read_story_from_string(content).unwrap();
+    /// let mut line_buffer = Vec::new();
+    ///
+    /// story.start().unwrap();
+    /// story.resume(&mut line_buffer).unwrap();
+    ///
+    /// story.restart();
+    ///
+    /// story.start().unwrap();
+    /// story.resume(&mut line_buffer).unwrap();
+    ///
+    /// assert_eq!(&line_buffer[0].text, "Once upon a time.\n");
+    /// assert_eq!(&line_buffer[1].text, "Once upon a time.\n");
+    /// ```
+    pub fn restart(&mut self) {
+        self.knots
+            .values_mut()
+            .flat_map(|knot| knot.stitches.values_mut())
+            .for_each(|stitch| stitch.reset());
+
+        self.data.knot_visit_counts = get_empty_knot_counts(&self.knots);
+
+        // The root address is always the first item on the stack
+        self.stack.truncate(1);
+
+        self.last_choices = None;
+        self.selected_choice = None;
+        self.in_progress = false;
+
+        if self.clear_transcript_on_restart {
+            self.transcript.clear();
+        }
+    }
+
+    /// Restart the story with variables and location restored from a saved story.
+    ///
+    /// This is meant for game flows where a "new game" save carries configuration that
+    /// should survive a fresh start. The story is [restarted][crate::story::Story::restart()]
+    /// and its global variables are reset to the values from the story file. Then the
+    /// variable values and current location of the saved story are set. Visit counts,
+    /// alternative sequences and choices are not taken from the save: they begin anew.
+    ///
+    /// The saved story may have been created from an older version of the story file.
+    /// Variables which are not present in this story are ignored and variables
+    /// which were added since the save was made keep their values from the story file.
+    /// The saved location and any addresses held by variables must still exist in
+    /// the story, otherwise an error is returned. If an error is returned, the story
+    /// is not modified.
+    ///
+    /// # Examples
+    /// ```
+    /// # use inkling::{read_story_from_string, Variable};
+    /// let content = "\
+    /// VAR difficulty = 1
+    ///
+    /// === tutorial ===
+    /// Press A to jump.
+    ///
+    /// === chapter_one ===
+    /// The night was cold.
+    /// ";
+    ///
+    /// let mut new_game = read_story_from_string(content).unwrap();
+    /// new_game.set_variable("difficulty", 3).unwrap();
+    /// new_game.move_to("chapter_one", None).unwrap();
+    ///
+    /// let mut story = read_story_from_string(content).unwrap();
+    /// let mut line_buffer = Vec::new();
+    ///
+    /// story.restart_from_save_defaults(&new_game).unwrap();
+    ///
+    /// story.start().unwrap();
+    /// story.resume(&mut line_buffer).unwrap();
+    ///
+    /// assert_eq!(story.get_variable("difficulty").unwrap(), Variable::Int(3));
+    /// assert_eq!(&line_buffer[0].text, "The night was cold.\n");
+    /// ```
+    ///
+    /// # Errors
+    /// *   [`InvalidAddress`][crate::error::InklingError::InvalidAddress]: if the location
+    ///     of the saved story or an address in one of its variables does not exist
+    ///     in this story.
+    /// *   [`VariableError`][crate::error::InklingError::VariableError]: if a saved
+    ///     variable has a different type to the variable in this story.
+    pub fn restart_from_save_defaults(&mut self, save: &Story) -> Result<(), InklingError> {
+        let (address, variables) = self.get_merged_save_state(save)?;
+
+        self.restart();
+        self.data.variables = variables;
+        self.update_last_stack(&address);
+
+        Ok(())
+    }
+
+    /// Get the location and initial variables of this story merged with those of a save.
+    ///
+    /// Only variables which exist in both stories are merged. All addresses are validated
+    /// against the knots of this story.
+    fn get_merged_save_state(&self, save: &Story) -> Result<(Address, VariableSet), InklingError> {
+        let (knot, stitch) = save.get_current_location()?;
+
+        let address = Address::from_parts(&knot, stitch.as_deref(), &self.knots).map_err(|_| {
+            InklingError::InvalidAddress {
+                knot: knot.clone(),
+                stitch: stitch.clone(),
+            }
+        })?;
+
+        // Stories saved before the initial variables were recorded have none: then the
+        // current values are the best available substitute.
+        let mut variables = if self.initial_variables.is_empty() {
+            self.data.variables.clone()
+        } else {
+            self.initial_variables.clone()
+        };
+
+        for (name, saved_info) in save.data.variables.iter() {
+            if let Some(variable_info) = variables.get_mut(name) {
+                check_variable_address(&saved_info.variable, &self.knots)?;
+                variable_info.variable.assign(saved_info.variable.clone())?;
+            }
+        }
+
+        Ok((address, variables))
+    }
+
     /// Wrapper for calling `follow_story` with a prepared internal buffer.
     ///
     /// Updates the stack to the last visited address and the last presented set of choices
@@ -570,12 +986,24 @@ impl Story {
             &mut self.data,
         )?;
 
+        let num_lines_before = line_buffer.len();
+
         process_buffer(line_buffer, internal_buffer);
 
+        if self.transcript_enabled {
+            self.transcript.extend(
+                line_buffer[num_lines_before..]
+                    .iter()
+                    .cloned()
+                    .map(TranscriptEntry::Line),
+            );
+        }
+
         self.update_last_stack(&last_address);
 
         match result {
-            Prompt::Choice(choices) => {
+            Prompt::Choice(mut choices) => {
+                self.choice_numbering.apply(&mut choices);
                 self.last_choices.replace(choices.clone());
 
                 Ok(Prompt::Choice(choices))
@@ -615,7 +1043,7 @@ pub fn read_story_from_string(string: &str) -> Result<Story, ReadError> {
         variables,
     };
 
-    validate_story_content(&mut knots, &data)?;
+    let warnings = validate_story_content(&mut knots, &data)?;
 
     let root_address = Address::from_root_knot(ROOT_KNOT_NAME, &knots).expect(
         "After successfully creating all knots, the root knot name that was returned from \
@@ -623,14 +1051,22 @@ pub fn read_story_from_string(string: &str) -> Result<Story, ReadError> {
          This simply should not be possible",
     );
 
+    let initial_variables = data.variables.clone();
+
     Ok(Story {
         knots,
         stack: vec![root_address],
         data,
+        initial_variables,
         tags,
+        warnings,
         last_choices: None,
         selected_choice: None,
         in_progress: false,
+        choice_numbering: ChoiceNumbering::default(),
+        transcript_enabled: false,
+        clear_transcript_on_restart: true,
+        transcript: Vec::new(),
     })
 }
 
@@ -722,13 +1158,48 @@ fn get_fallback_choice(
     })
 }
 
+/// Assert that a location held by a variable exists in the given set of knots.
+///
+/// Variables which do not hold a location are always valid. Divert addresses in global
+/// variables are not validated when the story is read, so raw addresses are also checked.
+fn check_variable_address(variable: &Variable, knots: &KnotSet) -> Result<(), InklingError> {
+    let (knot, stitch) = match variable {
+        Variable::Address(address) | Variable::Divert(address) => match address {
+            Address::Validated(AddressKind::Location { knot, stitch }) => {
+                (knot.clone(), Some(stitch.clone()))
+            }
+            Address::Raw(target) if target != DONE_KNOT && target != END_KNOT => {
+                let mut parts = target.splitn(2, '.');
+
+                let knot = parts.next().unwrap_or_default().to_string();
+                let stitch = parts.next().map(|stitch| stitch.to_string());
+
+                (knot, stitch)
+            }
+            _ => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+
+    Address::from_parts(&knot, stitch.as_deref(), knots)
+        .map(|_| ())
+        .map_err(|_| InklingError::InvalidAddress { knot, stitch })
+}
+
+/// Check whether a tag matches the query, either in full or by the key of a `key: value` tag.
+fn tag_matches(tag: &str, query: &str) -> bool {
+    let query = query.trim();
+
+    tag.trim() == query || tag.split(':').next().map(|key| key.trim()) == Some(query)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use crate::{
         knot::{get_num_visited, increment_num_visited},
-        story::parse::tests::read_knots_from_string,
+        story::{parse::tests::read_knots_from_string, Line},
     };
 
     use std::collections::HashMap;
@@ -1698,6 +2169,62 @@ Once back home we feasted on cheese.
         }
     }
 
+    #[test]
+    fn knots_with_tag_returns_all_knots_marked_with_the_tag_in_sorted_order() {
+        let content = "
+
+== tripoli
+# combat
+-> END
+
+== cairo
+# combat
+-> END
+
+== addis_ababa
+# trade
+-> END
+
+";
+
+        let story = read_story_from_string(content).unwrap();
+
+        assert_eq!(
+            &story.knots_with_tag("combat"),
+            &["cairo".to_string(), "tripoli".to_string()]
+        );
+        assert_eq!(&story.knots_with_tag("trade"), &["addis_ababa".to_string()]);
+        assert!(story.knots_with_tag("stealth").is_empty());
+    }
+
+    #[test]
+    fn knots_with_tag_matches_key_value_tags_by_key_or_full_tag() {
+        let content = "
+
+== tripoli
+# country: Libya
+-> END
+
+== cairo
+# country: Egypt
+# capital
+-> END
+
+";
+
+        let story = read_story_from_string(content).unwrap();
+
+        assert_eq!(
+            &story.knots_with_tag("country"),
+            &["cairo".to_string(), "tripoli".to_string()]
+        );
+        assert_eq!(
+            &story.knots_with_tag("country: Libya"),
+            &["tripoli".to_string()]
+        );
+        assert!(story.knots_with_tag("Libya").is_empty());
+    }
+
     #[test]
     fn current_location_in_story_is_the_latest_address_pushed_on_the_stack() {
         let content = "
@@ -1781,6 +2308,74 @@ Once back home we feasted on cheese.
             .is_err());
     }
 
+    #[test]
+    fn setting_number_of_visits_changes_count_of_knot_or_stitch() {
+        let content = "
+== hurry_home
+We hurried home as fast as we could.
+-> END
+
+= at_home
+Once back home we feasted on cheese.
+-> END
+
+";
+
+        let mut story = read_story_from_string(content).unwrap();
+
+        story.set_visit_count("hurry_home", None, 3).unwrap();
+        story
+            .set_visit_count("hurry_home", Some("at_home"), 5)
+            .unwrap();
+
+        assert_eq!(story.get_num_visited("hurry_home", None).unwrap(), 3);
+        assert_eq!(
+            story
+                .get_num_visited("hurry_home", Some("at_home"))
+                .unwrap(),
+            5
+        );
+    }
+
+    #[test]
+    fn set_number_of_visits_is_used_in_conditions() {
+        let content = "
+{hurry_home == 3: We knew the way home by now.|We hurried home as fast as we could.}
+-> hurry_home
+
+== hurry_home
+-> END
+
+";
+
+        let mut story = read_story_from_string(content).unwrap();
+        let mut line_buffer = Vec::new();
+
+        story.set_visit_count("hurry_home", None, 3).unwrap();
+
+        story.start().unwrap();
+        story.resume(&mut line_buffer).unwrap();
+
+        assert_eq!(&line_buffer[0].text, "We knew the way home by now.\n");
+    }
+
+    #[test]
+    fn setting_number_of_visits_yields_error_if_knot_or_stitch_name_is_invalid() {
+        let content = "
+== hurry_home
+We hurried home as fast as we could.
+-> END
+
+";
+
+        let mut story = read_story_from_string(content).unwrap();
+
+        assert!(story.set_visit_count("fin", None, 1).is_err());
+        assert!(story
+            .set_visit_count("hurry_home", Some("with_family"), 1)
+            .is_err());
+    }
+
     #[test]
     fn getting_variable_returns_cloned() {
         let content = "
@@ -1892,4 +2487,320 @@ VAR message = \"boring text\"
             ]
         );
     }
+
+    #[test]
+    fn restarting_story_resets_visits_alternatives_choices_and_location() {
+        let content = "
+-> hallway
+
+== hallway
+You are in the {first|second|third} hallway.
+*   Open the door -> cellar
++   Wait -> hallway
+
+== cellar
+-> END
+";
+
+        let mut story = read_story_from_string(content).unwrap();
+        let mut line_buffer = Vec::new();
+
+        story.start().unwrap();
+        story.resume(&mut line_buffer).unwrap();
+        story.make_choice(1).unwrap();
+        story.resume(&mut line_buffer).unwrap();
+        story.make_choice(0).unwrap();
+        story.resume(&mut line_buffer).unwrap();
+
+        story.restart();
+
+        assert!(!story.in_progress);
+        assert!(story.last_choices.is_none());
+        assert_eq!(story.get_current_location().unwrap().0, ROOT_KNOT_NAME);
+        assert_eq!(story.get_num_visited("hallway", None).unwrap(), 0);
+        assert_eq!(story.get_num_visited("cellar", None).unwrap(), 0);
+
+        line_buffer.clear();
+
+        story.start().unwrap();
+        let choices = story
+            .resume(&mut line_buffer)
+            .unwrap()
+            .get_choices()
+            .unwrap();
+
+        assert_eq!(&line_buffer[0].text, "You are in the first hallway.\n");
+        assert_eq!(choices.len(), 2);
+    }
+
+    #[test]
+    fn restarting_story_keeps_variable_values() {
+        let content = "
+VAR coins = 5
+Content.
+";
+
+        let mut story = read_story_from_string(content).unwrap();
+        story.set_variable("coins", 10).unwrap();
+
+        story.restart();
+
+        assert_eq!(story.get_variable("coins").unwrap(), Variable::Int(10));
+    }
+
+    #[test]
+    fn restart_from_save_defaults_sets_saved_variables_and_location_with_fresh_state() {
+        let content = "
+VAR difficulty = 1
+VAR player_name = \"Player\"
+VAR checkpoint = -> tutorial
+
+-> tutorial
+
+== tutorial
+This is tutorial number {tutorial}.
+-> chapter_one
+
+== chapter_one
+Welcome to {&the first|another} chapter, {player_name}.
+-> END
+";
+
+        let mut save = read_story_from_string(content).unwrap();
+        save.set_variable("difficulty", 3).unwrap();
+        save.set_variable("player_name", "Aliide").unwrap();
+        save.move_to("chapter_one", None).unwrap();
+
+        let mut story = read_story_from_string(content).unwrap();
+        let mut line_buffer = Vec::new();
+
+        story.set_variable("difficulty", 2).unwrap();
+        story.start().unwrap();
+        story.resume(&mut line_buffer).unwrap();
+
+        story.restart_from_save_defaults(&save).unwrap();
+
+        assert_eq!(story.get_variable("difficulty").unwrap(), Variable::Int(3));
+        assert_eq!(
+            story.get_variable("player_name").unwrap(),
+            Variable::from("Aliide")
+        );
+        assert_eq!(
+            story.get_current_location().unwrap(),
+            ("chapter_one".to_string(), None)
+        );
+        assert_eq!(story.get_num_visited("tutorial", None).unwrap(), 0);
+        assert_eq!(story.get_num_visited("chapter_one", None).unwrap(), 0);
+
+        line_buffer.clear();
+
+        story.start().unwrap();
+        story.resume(&mut line_buffer).unwrap();
+
+        assert_eq!(
+            &line_buffer[0].text,
+            "Welcome to the first chapter, Aliide.\n"
+        );
+        assert_eq!(story.get_num_visited("chapter_one", None).unwrap(), 1);
+    }
+
+    #[test]
+    fn restart_from_save_defaults_resets_variables_that_are_not_in_the_save() {
+        let old_content = "
+VAR difficulty = 1
+Content.
+";
+
+        let new_content = "
+VAR difficulty = 1
+VAR hardcore = false
+Content.
+";
+
+        let mut save = read_story_from_string(old_content).unwrap();
+        save.set_variable("difficulty", 3).unwrap();
+
+        let mut story = read_story_from_string(new_content).unwrap();
+        story.set_variable("hardcore", true).unwrap();
+
+        story.restart_from_save_defaults(&save).unwrap();
+
+        assert_eq!(story.get_variable("difficulty").unwrap(), Variable::Int(3));
+        assert_eq!(
+            story.get_variable("hardcore").unwrap(),
+            Variable::Bool(false)
+        );
+    }
+
+    #[test]
+    fn restart_from_save_defaults_yields_error_if_saved_location_is_not_in_story() {
+        let old_content = "
+VAR difficulty = 1
+Content.
+
+== removed_chapter
+Content.
+";
+
+        let new_content = "
+VAR difficulty = 1
+Content.
+";
+
+        let mut save = read_story_from_string(old_content).unwrap();
+        save.set_variable("difficulty", 3).unwrap();
+        save.move_to("removed_chapter", None).unwrap();
+
+        let mut story = read_story_from_string(new_content).unwrap();
+        story.set_variable("difficulty", 2).unwrap();
+
+        match story.restart_from_save_defaults(&save) {
+            Err(InklingError::InvalidAddress { knot, stitch }) => {
+                assert_eq!(&knot, "removed_chapter");
+                assert!(stitch.is_none());
+            }
+            other => panic!(
+                "expected `InklingError::InvalidAddress` but got {:?}",
+                other
+            ),
+        }
+
+        assert_eq!(story.get_variable("difficulty").unwrap(), Variable::Int(2));
+    }
+
+    #[test]
+    fn restart_from_save_defaults_yields_error_if_saved_divert_variable_is_not_in_story() {
+        let old_content = "
+VAR checkpoint = -> removed_chapter
+Content.
+
+== removed_chapter
+Content.
+";
+
+        let new_content = "
+VAR checkpoint = -> chapter
+Content.
+
+== chapter
+Content.
+";
+
+        let save = read_story_from_string(old_content).unwrap();
+        let mut story = read_story_from_string(new_content).unwrap();
+
+        match story.restart_from_save_defaults(&save) {
+            Err(InklingError::InvalidAddress { knot, .. }) => {
+                assert_eq!(&knot, "removed_chapter");
+            }
+            other => panic!(
+                "expected `InklingError::InvalidAddress` but got {:?}",
+                other
+            ),
+        }
+    }
+
+    #[test]
+    fn transcript_is_not_recorded_unless_enabled() {
+        let content = "
+Content.
+*   Choice
+";
+
+        let mut story = read_story_from_string(content).unwrap();
+        let mut line_buffer = Vec::new();
+
+        story.start().unwrap();
+        story.resume(&mut line_buffer).unwrap();
+        story.make_choice(0).unwrap();
+
+        assert!(story.transcript().is_empty());
+    }
+
+    #[test]
+    fn transcript_records_only_lines_produced_while_resuming() {
+        let content = "
+Content.
+*   Choice
+";
+
+        let mut story = read_story_from_string(content).unwrap();
+        let mut line_buffer = vec![Line {
+            text: "Line from the caller.\n".to_string(),
+            tags: Vec::new(),
+        }];
+
+        story.enable_transcript(true);
+
+        story.start().unwrap();
+        story.resume(&mut line_buffer).unwrap();
+
+        assert_eq!(
+            story.transcript(),
+            vec![TranscriptEntry::Line(line_buffer[1].clone())]
+        );
+    }
+
+    #[test]
+    fn disabling_transcript_keeps_recorded_entries() {
+        let content = "
+Content.
+*   Choice
+";
+
+        let mut story = read_story_from_string(content).unwrap();
+        let mut line_buffer = Vec::new();
+
+        story.enable_transcript(true);
+
+        story.start().unwrap();
+        story.resume(&mut line_buffer).unwrap();
+
+        story.enable_transcript(false);
+        story.make_choice(0).unwrap();
+
+        assert_eq!(story.transcript().len(), 1);
+    }
+
+    #[test]
+    fn restarting_story_clears_transcript_by_default() {
+        let content = "
+Content.
+";
+
+        let mut story = read_story_from_string(content).unwrap();
+        let mut line_buffer = Vec::new();
+
+        story.enable_transcript(true);
+
+        story.start().unwrap();
+        story.resume(&mut line_buffer).unwrap();
+
+        story.restart();
+
+        assert!(story.transcript().is_empty());
+    }
+
+    #[test]
+    fn restarting_story_can_keep_transcript_to_append_to() {
+        let content = "
+Content.
+";
+
+        let mut story = read_story_from_string(content).unwrap();
+        let mut line_buffer = Vec::new();
+
+        story.enable_transcript(true);
+        story.set_clear_transcript_on_restart(false);
+
+        story.start().unwrap();
+        story.resume(&mut line_buffer).unwrap();
+
+        story.restart();
+
+        story.start().unwrap();
+        story.resume(&mut line_buffer).unwrap();
+
+        assert_eq!(story.transcript().len(), 2);
+    }
 }
@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde_support", derive(Deserialize, Serialize))]
 /// Single line of text in a story, ready to display.
 pub struct Line {
     /// Text to display.
@@ -35,6 +36,72 @@ pub struct Choice {
     pub(crate) index: usize,
 }
 
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde_support", derive(Deserialize, Serialize))]
+/// Style of labels to prefix the text of presented choices with.
+///
+/// Set to a story with [`set_choice_numbering`][crate::story::Story::set_choice_numbering()].
+pub enum ChoiceNumbering {
+    /// Choices are presented with their text as-is.
+    None,
+    /// Choices are numbered from 1: `1. `, `2. `, `3. `, ...
+    Numbers,
+    /// Choices are labeled with lowercase letters: `a) `, `b) `, `c) `, ...
+    ///
+    /// After `z)` the labels continue with `aa)`, `ab)` and so on.
+    Letters,
+}
+
+impl Default for ChoiceNumbering {
+    fn default() -> Self {
+        ChoiceNumbering::None
+    }
+}
+
+impl ChoiceNumbering {
+    /// Get the label for a choice at the given index in the presented set, if any.
+    pub(crate) fn get_label(&self, index: usize) -> Option<String> {
+        match self {
+            ChoiceNumbering::None => None,
+            ChoiceNumbering::Numbers => Some(format!("{}.", index + 1)),
+            ChoiceNumbering::Letters => {
+                let mut letters = Vec::new();
+                let mut remainder = index + 1;
+
+                while remainder > 0 {
+                    remainder -= 1;
+                    letters.push((b'a' + (remainder % 26) as u8) as char);
+                    remainder /= 26;
+                }
+
+                Some(format!("{})", letters.iter().rev().collect::<String>()))
+            }
+        }
+    }
+
+    /// Prefix the text of all choices in the set with their label.
+    pub(crate) fn apply(&self, choices: &mut [Choice]) {
+        for (i, choice) in choices.iter_mut().enumerate() {
+            if let Some(label) = self.get_label(i) {
+                choice.text = format!("{} {}", label, choice.text);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde_support", derive(Deserialize, Serialize))]
+/// Entry in the transcript of a story playthrough.
+///
+/// Recorded by a story after [`enable_transcript`][crate::story::Story::enable_transcript()]
+/// has been called on it.
+pub enum TranscriptEntry {
+    /// Line of text that was produced by the story.
+    Line(Line),
+    /// Choice that was made by the user, as it was presented.
+    Choice(Choice),
+}
+
 #[derive(Debug)]
 /// Result from following a `Story`.
 ///
@@ -123,3 +190,29 @@ impl VariableInfo {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn choice_numbering_labels_count_from_one() {
+        assert_eq!(ChoiceNumbering::Numbers.get_label(0).unwrap(), "1.");
+        assert_eq!(ChoiceNumbering::Numbers.get_label(11).unwrap(), "12.");
+    }
+
+    #[test]
+    fn choice_letter_labels_continue_with_two_letters_after_z() {
+        let numbering = ChoiceNumbering::Letters;
+
+        assert_eq!(numbering.get_label(0).unwrap(), "a)");
+        assert_eq!(numbering.get_label(25).unwrap(), "z)");
+        assert_eq!(numbering.get_label(26).unwrap(), "aa)");
+        assert_eq!(numbering.get_label(27).unwrap(), "ab)");
+    }
+
+    #[test]
+    fn no_choice_numbering_gives_no_labels() {
+        assert!(ChoiceNumbering::None.get_label(0).is_none());
+    }
+}
@@ -1,7 +1,10 @@
 //! Trait and functions to validate a story.
 
 use crate::{
-    error::{parse::validate::ValidationError, utils::MetaData},
+    error::{
+        parse::validate::{ValidationError, ValidationWarning},
+        utils::MetaData,
+    },
     follow::FollowData,
     knot::{get_empty_knot_counts, Address, AddressKind, KnotSet},
     story::{types::VariableSet, validate::namespace::validate_story_name_spaces},
@@ -96,7 +99,8 @@ impl ValidationData {
 /// expressions which also need to be validated, and so on.
 ///
 /// All encountered errors will be recorded in the error container but not break the nested
-/// search since we want to collect all possible errors at once. To assert whether an error
+/// search since we want to collect all possible errors at once. Warnings about content which
+/// is valid but likely a mistake are recorded in a separate collection. To assert whether an error
 /// was found we simply check whether this container is empty or not. For this use case this
 /// is easier than returning a `Result`.
 ///
@@ -112,6 +116,7 @@ pub trait ValidateContent {
     fn validate(
         &mut self,
         errors: &mut ValidationError,
+        warnings: &mut Vec<ValidationWarning>,
         current_location: &Address,
         current_meta_data: &MetaData,
         follow_data: &ValidationData,
@@ -124,14 +129,16 @@ pub trait ValidateContent {
 /// uses the `ValidateContent` trait to nest through its content. Additionally it checks for
 /// name space collisions between variables, knots and stitches.
 ///
-/// If any error is encountered this will yield the set of all found errors.
+/// If any error is encountered this will yield the set of all found errors. Otherwise
+/// any warnings about content that is valid but likely a mistake are returned.
 pub fn validate_story_content(
     knots: &mut KnotSet,
     follow_data: &FollowData,
-) -> Result<(), ValidationError> {
+) -> Result<Vec<ValidationWarning>, ValidationError> {
     let validation_data = ValidationData::from_data(knots, &follow_data.variables);
 
     let mut error = ValidationError::new();
+    let mut warnings = Vec::new();
 
     knots.iter_mut().for_each(|(knot_name, knot)| {
         knot.stitches.iter_mut().for_each(|(stitch_name, stitch)| {
@@ -142,6 +149,7 @@ pub fn validate_story_content(
 
             stitch.root.validate(
                 &mut error,
+                &mut warnings,
                 &current_location,
                 &stitch.meta_data,
                 &validation_data,
@@ -154,7 +162,7 @@ pub fn validate_story_content(
     }
 
     if error.is_empty() {
-        Ok(())
+        Ok(warnings)
     } else {
         Err(error)
     }
@@ -236,7 +244,9 @@ pub(super) mod tests {
         (knots, data)
     }
 
-    fn get_validation_result_from_string(content: &str) -> Result<(), ValidationError> {
+    fn get_validation_result_from_string(
+        content: &str,
+    ) -> Result<Vec<ValidationWarning>, ValidationError> {
         let (mut knots, data) = get_validation_data_from_string(content);
         validate_story_content(&mut knots, &data)
     }
@@ -436,6 +446,144 @@ Line one.
         assert_eq!(error.invalid_address_errors.len(), 1);
     }
 
+    #[test]
+    fn validating_story_warns_about_conditions_which_are_always_true_or_false() {
+        let content = "
+
+VAR variable = 1
+
+*   {false} Choice 1
+*   {1 == 1} Choice 2
+*   {variable == 1} Choice 3
+{not true: Line one. | Line two.}
+
+";
+
+        let warnings = get_validation_result_from_string(content).unwrap();
+
+        assert_eq!(warnings.len(), 3);
+        assert!(warnings.contains(&ValidationWarning::ConstantCondition {
+            value: false,
+            meta_data: 4.into(),
+        }));
+        assert!(warnings.contains(&ValidationWarning::ConstantCondition {
+            value: true,
+            meta_data: 5.into(),
+        }));
+        assert!(warnings.contains(&ValidationWarning::ConstantCondition {
+            value: false,
+            meta_data: 7.into(),
+        }));
+    }
+
+    #[test]
+    fn conditions_with_variables_are_constant_if_determined_by_other_items() {
+        let content = "
+
+VAR variable = 1
+
+*   {variable > 2 and false} Choice 1
+*   {variable > 2 or (2 > 1)} Choice 2
+*   {variable > 2 and (2 > 1)} Choice 3
+*   {knot and true} Choice 4
+
+== knot
+Line.
+
+";
+
+        let warnings = get_validation_result_from_string(content).unwrap();
+
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings.contains(&ValidationWarning::ConstantCondition {
+            value: false,
+            meta_data: 4.into(),
+        }));
+        assert!(warnings.contains(&ValidationWarning::ConstantCondition {
+            value: true,
+            meta_data: 5.into(),
+        }));
+    }
+
+    #[test]
+    fn nested_constant_conditions_do_not_raise_separate_warnings() {
+        let content = "
+
+VAR variable = 1
+
+*   {variable > 2 and (true or false)} Choice 1
+
+";
+
+        let warnings = get_validation_result_from_string(content).unwrap();
+
+        assert!(warnings.is_empty());
+    }
+
+    #[test]
+    fn every_constant_condition_in_a_line_is_warned_about() {
+        let content = "
+
+Hi {false: a} and {false: b}.
+
+";
+
+        let warnings = get_validation_result_from_string(content).unwrap();
+
+        assert_eq!(
+            &warnings,
+            &[
+                ValidationWarning::ConstantCondition {
+                    value: false,
+                    meta_data: 2.into(),
+                },
+                ValidationWarning::ConstantCondition {
+                    value: false,
+                    meta_data: 2.into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn constant_conditions_inside_choice_text_are_warned_about_once() {
+        let content = "
+
+*   Hello {false: x|y} there
+*   [Bye {true: a|b}] {1 > 2: Bye!}
+*   Hi {false: a} and {false: b}
+
+";
+
+        let warnings = get_validation_result_from_string(content).unwrap();
+
+        assert_eq!(
+            &warnings,
+            &[
+                ValidationWarning::ConstantCondition {
+                    value: false,
+                    meta_data: 2.into(),
+                },
+                ValidationWarning::ConstantCondition {
+                    value: true,
+                    meta_data: 3.into(),
+                },
+                ValidationWarning::ConstantCondition {
+                    value: false,
+                    meta_data: 3.into(),
+                },
+                ValidationWarning::ConstantCondition {
+                    value: false,
+                    meta_data: 4.into(),
+                },
+                ValidationWarning::ConstantCondition {
+                    value: false,
+                    meta_data: 4.into(),
+                },
+            ]
+        );
+    }
+
     #[test]
     fn validating_story_sets_all_addresses_to_validated_addresses() {
         let content = "
@@ -528,3 +528,38 @@ A crossing! Which path do you take?
 
     assert_eq!(&line_buffer[0].text, "This chamber used to hold a torch. ");
 }
+
+#[test]
+fn choices_with_conditions_that_are_always_false_raise_a_warning_and_are_never_shown() {
+    let content = "
+VAR coins = 3
+
+The merchant shows you his wares.
+*   {coins > 2} Buy the lantern.
+*   {2 > 3} Buy the sword.
+*   Leave.
+";
+
+    let mut story = read_story_from_string(content).unwrap();
+    let mut line_buffer = Vec::new();
+
+    let warnings = story.get_warnings();
+
+    assert_eq!(warnings.len(), 1);
+    assert_eq!(
+        format!("{}", warnings[0]),
+        "(line 6) Condition is always false: its content will never be shown"
+    );
+
+    story.start().unwrap();
+
+    let choices = story
+        .resume(&mut line_buffer)
+        .unwrap()
+        .get_choices()
+        .unwrap();
+
+    assert_eq!(choices.len(), 2);
+    assert_eq!(&choices[0].text, "Buy the lantern.");
+    assert_eq!(&choices[1].text, "Leave.");
+}
@@ -581,6 +581,61 @@ she sent me to a boarding school in England ...
     );
 }
 
+#[test]
+fn glue_binds_choice_output_to_the_following_lines() {
+    let content = "
+
+The train pulls into the station.
+*   [Step off] You step off the train <>
+-   <> and onto the platform.
+    -> platform
+
+== platform
+It is nearly empty.
+
+";
+
+    let mut story = read_story_from_string(content).unwrap();
+    let mut line_buffer = Vec::new();
+
+    story.start().unwrap();
+    story.resume(&mut line_buffer).unwrap();
+    story.make_choice(0).unwrap();
+
+    line_buffer.clear();
+    story.resume(&mut line_buffer).unwrap();
+
+    assert_eq!(&line_buffer[0].text, "You step off the train ");
+    assert_eq!(&line_buffer[1].text, "and onto the platform.\n");
+    assert_eq!(&line_buffer[2].text, "It is nearly empty.\n");
+}
+
+#[test]
+fn glue_in_choice_output_without_text_binds_the_previous_line_to_the_next() {
+    let content = "
+
+You wait for the train.
+*   <> -> platform
+
+== platform
+ It arrives.
+
+";
+
+    let mut story = read_story_from_string(content).unwrap();
+    let mut line_buffer = Vec::new();
+
+    story.start().unwrap();
+    story.resume(&mut line_buffer).unwrap();
+
+    let text = line_buffer
+        .iter()
+        .map(|line| line.text.clone())
+        .collect::<String>();
+
+    assert_eq!(text, "You wait for the train. It arrives.\n");
+}
+
 #[test]
 fn tags_are_included_with_lines_and_choices() {
     let content = "
@@ -615,3 +670,247 @@ An old castle heaves in front of you. -> gates # description
     assert_eq!(&choices[0].text, "Enter it.");
     assert_eq!(&choices[0].tags, &["action".to_string()]);
 }
+
+#[test]
+fn choice_numbering_prefixes_presented_choices_in_order() {
+    let content = "
+
+== harbour
+The harbour master eyes you suspiciously.
++   <> Show him your papers. # papers
+*   Bribe him.
+*   {harbour > 1} Remind him of your last visit.
++   [Leave] -> harbour
+
+";
+
+    let mut story = read_story_from_string(content).unwrap();
+    let mut line_buffer = Vec::new();
+
+    story.set_choice_numbering(ChoiceNumbering::Letters);
+    story.move_to("harbour", None).unwrap();
+    story.start().unwrap();
+
+    let choices = story
+        .resume(&mut line_buffer)
+        .unwrap()
+        .get_choices()
+        .unwrap();
+
+    assert_eq!(choices.len(), 3);
+    assert_eq!(&choices[0].text, "a) Show him your papers.");
+    assert_eq!(&choices[0].tags, &["papers".to_string()]);
+    assert_eq!(&choices[1].text, "b) Bribe him.");
+    assert_eq!(&choices[2].text, "c) Leave");
+
+    story.set_choice_numbering(ChoiceNumbering::Numbers);
+    story.make_choice(2).unwrap();
+
+    let choices = story
+        .resume(&mut line_buffer)
+        .unwrap()
+        .get_choices()
+        .unwrap();
+
+    assert_eq!(choices.len(), 4);
+    assert_eq!(&choices[0].text, "1. Show him your papers.");
+    assert_eq!(&choices[1].text, "2. Bribe him.");
+    assert_eq!(&choices[2].text, "3. Remind him of your last visit.");
+    assert_eq!(&choices[3].text, "4. Leave");
+}
+
+#[test]
+fn top_level_lines_and_diverts_are_followed_in_order_before_the_first_knot() {
+    let content = "\
+# title: top_level_flow
+VAR visits = 0
+
+The rain had not stopped for three days.
+Gesicht turned up the collar of his coat.
+-> root
+
+== root
+A car waits for him by the curb.
+-> drive
+
+== drive
+The driver does not say a word.
+";
+
+    let mut story = read_story_from_string(content).unwrap();
+    let mut line_buffer = Vec::new();
+
+    story.start().unwrap();
+
+    match story.resume(&mut line_buffer) {
+        Ok(Prompt::Done) => {
+            assert_eq!(line_buffer.len(), 4);
+            assert_eq!(
+                &line_buffer[0].text,
+                "The rain had not stopped for three days.\n"
+            );
+            assert_eq!(
+                &line_buffer[1].text,
+                "Gesicht turned up the collar of his coat.\n"
+            );
+            assert_eq!(&line_buffer[2].text, "A car waits for him by the curb.\n");
+            assert_eq!(&line_buffer[3].text, "The driver does not say a word.\n");
+        }
+        Ok(_) => panic!("unexpected choice was encountered"),
+        Err(err) => panic!("error while reading a flat story from string: {:?}", err),
+    }
+}
+
+#[test]
+fn top_level_diverts_inside_choices_are_followed_from_the_top() {
+    let content = "\
+The rain had not stopped for three days.
+*   [Wait] -> root
+*   [Leave] -> leave
+
+== root
+A car waits for him by the curb.
+
+== leave
+Gesicht walks off into the rain.
+";
+
+    let mut story = read_story_from_string(content).unwrap();
+    let mut line_buffer = Vec::new();
+
+    story.start().unwrap();
+    story.resume(&mut line_buffer).unwrap();
+
+    story.make_choice(1).unwrap();
+    line_buffer.clear();
+
+    story.resume(&mut line_buffer).unwrap();
+
+    assert_eq!(line_buffer.len(), 1);
+    assert_eq!(&line_buffer[0].text, "Gesicht walks off into the rain.\n");
+}
+
+#[test]
+fn content_after_a_knot_header_belongs_to_the_knot_and_not_the_top_level() {
+    let content = "\
+The rain had not stopped for three days.
+-> DONE
+
+== root
+A car waits for him by the curb.
+";
+
+    let mut story = read_story_from_string(content).unwrap();
+    let mut line_buffer = Vec::new();
+
+    story.start().unwrap();
+
+    match story.resume(&mut line_buffer) {
+        Ok(Prompt::Done) => {
+            assert_eq!(line_buffer.len(), 1);
+            assert_eq!(
+                &line_buffer[0].text,
+                "The rain had not stopped for three days.\n"
+            );
+        }
+        Ok(_) => panic!("unexpected choice was encountered"),
+        Err(err) => panic!("error while reading a flat story from string: {:?}", err),
+    }
+}
+
+#[test]
+fn escaped_tag_markers_are_kept_as_text_in_line_and_choice_tags() {
+    let content = "
+The report \\#1138 lies on the desk. # case \\#1138 # evidence
+*   Read it. # page \\#2
+";
+
+    let mut story = read_story_from_string(content).unwrap();
+    let mut line_buffer = Vec::new();
+
+    story.start().unwrap();
+
+    let choices = story
+        .resume(&mut line_buffer)
+        .unwrap()
+        .get_choices()
+        .unwrap();
+
+    assert_eq!(&line_buffer[0].text, "The report #1138 lies on the desk.\n");
+    assert_eq!(
+        &line_buffer[0].tags,
+        &["case #1138".to_string(), "evidence".to_string()]
+    );
+    assert_eq!(&choices[0].tags, &["page #2".to_string()]);
+}
+
+#[test]
+fn escaped_tag_markers_are_kept_as_text_in_story_and_knot_tags() {
+    let content = "
+# archive \\#7
+
+-> office
+
+== office
+# issue \\#5
+The report lies on the desk.
+";
+
+    let story = read_story_from_string(content).unwrap();
+
+    assert_eq!(&story.get_story_tags(), &["archive #7".to_string()]);
+    assert_eq!(
+        &story.get_knot_tags("office").unwrap(),
+        &["issue #5".to_string()]
+    );
+}
+
+#[test]
+fn transcript_records_lines_and_choices_of_playthrough_in_order() {
+    let content = "
+The harbour master eyes you suspiciously.
+*   Show him your papers.
+    He waves you through.
+*   [Leave] You turn back to the ship.
+-   The ferry leaves at dawn.
+";
+
+    let mut story = read_story_from_string(content).unwrap();
+    let mut line_buffer = Vec::new();
+
+    story.enable_transcript(true);
+    story.start().unwrap();
+
+    let choices = story
+        .resume(&mut line_buffer)
+        .unwrap()
+        .get_choices()
+        .unwrap();
+
+    story.make_choice(0).unwrap();
+    story.resume(&mut line_buffer).unwrap();
+
+    let transcript = story.transcript();
+
+    assert_eq!(transcript.len(), 5);
+    assert_eq!(transcript[0], TranscriptEntry::Line(line_buffer[0].clone()));
+    assert_eq!(transcript[1], TranscriptEntry::Choice(choices[0].clone()));
+
+    let texts = transcript
+        .iter()
+        .filter_map(|entry| match entry {
+            TranscriptEntry::Line(line) => Some(line.text.as_str()),
+            TranscriptEntry::Choice(..) => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        &texts,
+        &[
+            "The harbour master eyes you suspiciously.\n",
+            "Show him your papers.\n",
+            "He waves you through.\n",
+            "The ferry leaves at dawn.\n",
+        ]
+    );
+}
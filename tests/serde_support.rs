@@ -72,4 +72,52 @@ You head back.
         assert_eq!(choices_without_torch.len(), 1);
         assert_eq!(choices_with_torch.len(), 2);
     }
+
+    #[test]
+    fn stories_saved_without_recently_added_fields_can_be_loaded() {
+        let content = "
+
+VAR difficulty = 1
+
+Press A to jump.
+
+";
+
+        let mut story = read_story_from_string(content).unwrap();
+        let mut line_buffer = Vec::new();
+
+        story.set_variable("difficulty", 3).unwrap();
+
+        let mut serialized = serde_json::to_value(&story).unwrap();
+        let fields = serialized.as_object_mut().unwrap();
+
+        for field in &[
+            "initial_variables",
+            "warnings",
+            "choice_numbering",
+            "transcript_enabled",
+            "clear_transcript_on_restart",
+            "transcript",
+        ] {
+            assert!(fields.remove(*field).is_some());
+        }
+
+        let mut saved_story: Story = serde_json::from_value(serialized).unwrap();
+
+        saved_story.enable_transcript(true);
+        saved_story.start().unwrap();
+        saved_story.resume(&mut line_buffer).unwrap();
+
+        assert_eq!(&line_buffer[0].text, "Press A to jump.\n");
+        assert_eq!(saved_story.transcript().len(), 1);
+
+        saved_story.restart();
+        assert!(saved_story.transcript().is_empty());
+
+        saved_story.restart_from_save_defaults(&story).unwrap();
+        assert_eq!(
+            saved_story.get_variable("difficulty").unwrap(),
+            Variable::Int(3)
+        );
+    }
 }
@@ -132,3 +132,40 @@ The latest measurement is {value} {unit}. {not is_hazardous: Not terrible, not g
         "The latest measurement is 15000 Röntgen. Oh no.\n"
     );
 }
+
+#[test]
+fn variable_types_can_be_retrieved_by_name() {
+    let content = "
+
+VAR value = 3.6
+VAR threshold = 10
+VAR unit = \"Röntgen\"
+VAR is_hazardous = false
+VAR next_step = -> evacuate
+
+-> evacuate
+
+== evacuate
+Everybody out!
+
+";
+
+    let mut story = read_story_from_string(content).unwrap();
+
+    assert_eq!(story.variable_type("value").unwrap(), VariableType::Float);
+    assert_eq!(story.variable_type("threshold").unwrap(), VariableType::Int);
+    assert_eq!(story.variable_type("unit").unwrap(), VariableType::String);
+    assert_eq!(
+        story.variable_type("is_hazardous").unwrap(),
+        VariableType::Bool
+    );
+    assert_eq!(
+        story.variable_type("next_step").unwrap(),
+        VariableType::DivertTarget
+    );
+
+    story.set_variable("threshold", 20).unwrap();
+    assert_eq!(story.variable_type("threshold").unwrap(), VariableType::Int);
+
+    assert!(story.variable_type("unknown").is_err());
+}